@@ -1,13 +1,19 @@
 use crate::cli_types::*;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use serde_json;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::process::Stdio;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
-use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, Command};
+use futures::stream::{self, StreamExt};
+use tokio::sync::{mpsc, oneshot, Mutex as AsyncMutex, RwLock};
 use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
 
 #[derive(Error, Debug)]
 pub enum CliError {
@@ -15,8 +21,8 @@ pub enum CliError {
     ExecutableNotFound(String),
     #[error("Schema version mismatch: expected {expected}, got {actual}")]
     SchemaMismatch { expected: String, actual: String },
-    #[error("Process execution failed: {0}")]
-    ProcessFailed(String),
+    #[error("Process exited with code {code}: {stderr}")]
+    ProcessFailed { code: i32, stderr: String },
     #[error("Process timeout after {seconds} seconds")]
     Timeout { seconds: u64 },
     #[error("JSON parsing error: {0}")]
@@ -27,42 +33,275 @@ pub enum CliError {
     CliError { code: String, message: String },
     #[error("Invalid parameters: {0}")]
     InvalidParameters(String),
+    #[error("Incompatible CLI version: {0}")]
+    Incompatible(String),
+    #[error("Cancelled: superseded by a newer request")]
+    Cancelled,
+}
+
+impl From<CliError> for TaxGlideError {
+    fn from(err: CliError) -> Self {
+        match err {
+            CliError::ExecutableNotFound(reason) => TaxGlideError::CliSpawn(reason),
+            CliError::SchemaMismatch { expected, actual } => TaxGlideError::CliNonZeroExit {
+                code: -1,
+                stderr: format!("schema mismatch: GUI expects {}, CLI reports {}", expected, actual),
+            },
+            CliError::ProcessFailed { code, stderr } => TaxGlideError::CliNonZeroExit { code, stderr },
+            CliError::Timeout { seconds } => TaxGlideError::Timeout { seconds },
+            CliError::JsonError(e) => TaxGlideError::Deserialize(e.to_string()),
+            CliError::IoError(e) => TaxGlideError::CliSpawn(e.to_string()),
+            CliError::CliError { message, .. } => TaxGlideError::CliNonZeroExit { code: -1, stderr: message },
+            CliError::InvalidParameters(reason) => TaxGlideError::Validation {
+                field: "params".to_string(),
+                reason,
+            },
+            CliError::Incompatible(reason) => TaxGlideError::Validation {
+                field: "cli_version".to_string(),
+                reason,
+            },
+            CliError::Cancelled => TaxGlideError::Cancelled,
+        }
+    }
+}
+
+/// Bounded exponential backoff policy for retrying transient CLI failures.
+///
+/// On attempt `n` the sleep is `min(initial_interval * multiplier^(n-1), max_interval)`
+/// plus random jitter in `[0, interval/2)`, to avoid a thundering herd of retries
+/// hammering a shared config file at the same instant.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub initial_interval: Duration,
+    pub multiplier: f64,
+    pub max_interval: Duration,
+}
+
+impl RetryConfig {
+    /// Policy for cheap read-only commands (calc/validate/compare_brackets):
+    /// several quick attempts since there's no risk of double-applying a
+    /// mutation. Long-running sweeps use [`RetryConfig::long_running`] instead.
+    pub fn read_only() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_interval: Duration::from_millis(150),
+            multiplier: 2.0,
+            max_interval: Duration::from_secs(2),
+        }
+    }
+
+    /// Policy for commands that mutate config on disk (create_year,
+    /// update_canton, ...): fewer attempts with a longer backoff, giving a
+    /// momentarily locked file more time to free up before giving up.
+    pub fn mutating() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_interval: Duration::from_millis(300),
+            multiplier: 2.5,
+            max_interval: Duration::from_secs(5),
+        }
+    }
+
+    /// Policy for long-running sweeps (optimize/scan): a 60s timeout on one
+    /// of these is often genuine ("this sweep needs more than 60s"), not
+    /// transient, so retrying it under `read_only`'s tight backoff would
+    /// just hang the caller for minutes and spawn several more CLI
+    /// processes on top of the one already timing out. No retries here;
+    /// a timeout is surfaced to the caller immediately instead.
+    pub fn long_running() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_interval: Duration::from_millis(150),
+            multiplier: 2.0,
+            max_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self::mutating()
+    }
+}
+
+/// Classifies whether a `CliError` is worth retrying.
+///
+/// IO errors (including a failed spawn), timeouts, and the CLI process
+/// exiting non-zero (`ProcessFailed`, whatever the cause) are retryable.
+/// Errors the CLI reports in its JSON response (validation, "canton already
+/// exists"), schema mismatches, invalid parameters and a missing executable
+/// are not, since retrying them only reproduces the same failure.
+pub fn is_retryable(err: &CliError) -> bool {
+    matches!(
+        err,
+        CliError::IoError(_) | CliError::Timeout { .. } | CliError::ProcessFailed { .. }
+    )
+}
+
+/// Cheap, dependency-free jitter source: no crate in this tree provides
+/// randomness, so derive a `[0, 1)` fraction from the low bits of the clock.
+fn jitter_fraction() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1_000_000) as f64 / 1_000_000.0
+}
+
+/// Runs `op` with bounded exponential backoff, retrying only errors that
+/// `is_retryable` classifies as transient. Logs each retry at `warn` and
+/// returns the final error unchanged once attempts are exhausted.
+pub async fn retry<T, F, Fut>(config: &RetryConfig, mut op: F) -> Result<T, CliError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, CliError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < config.max_attempts && is_retryable(&err) => {
+                let backoff = config
+                    .initial_interval
+                    .mul_f64(config.multiplier.powi(attempt as i32 - 1))
+                    .min(config.max_interval);
+                let jitter = backoff.mul_f64(jitter_fraction() * 0.5);
+                warn!(
+                    "Retrying transient CLI error (attempt {}/{}) after {:?}: {}",
+                    attempt, config.max_attempts, backoff + jitter, err
+                );
+                tokio::time::sleep(backoff + jitter).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// One classified line of NDJSON output from a `--progress`-enabled command.
+enum ProgressLine {
+    Progress(ProgressUpdate),
+    /// Raw JSON text of the `result` line's `data` field, still to be parsed
+    /// into a `CliResponse<T>` by the caller (which knows `T`).
+    Result(String),
+}
+
+/// Classify one stdout line from a `--progress`-enabled command: a
+/// `{"kind":"progress",...}` line becomes a `ProgressUpdate`, a
+/// `{"kind":"result","data":...}` line yields its `data` field, and
+/// anything that isn't a recognized JSON envelope is ignored.
+fn classify_progress_line(line: &str) -> Option<ProgressLine> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    match value.get("kind")?.as_str()? {
+        "progress" => serde_json::from_value(value).ok().map(ProgressLine::Progress),
+        "result" => Some(ProgressLine::Result(value.get("data")?.to_string())),
+        _ => None,
+    }
+}
+
+/// One pending RPC call: fulfilled with the `payload` of the response that
+/// carries the matching `id`, or with `Err` if the connection dies first.
+type PendingCalls = Arc<AsyncMutex<HashMap<u64, oneshot::Sender<Result<serde_json::Value, String>>>>>;
+
+/// A persistent `taxglide serve --rpc` child process. Requests are framed as
+/// one JSON object per line on the child's stdin (`{"id", "method", "params"}`);
+/// responses are one JSON object per line on stdout (`{"id", "payload"}`),
+/// read by a background task and routed back to the caller awaiting that id.
+/// This avoids paying the CLI's process + interpreter startup cost on every
+/// `calc`/`optimize`/`scan` call.
+struct RpcConnection {
+    child: AsyncMutex<Child>,
+    stdin: AsyncMutex<ChildStdin>,
+    pending: PendingCalls,
+    next_id: AtomicU64,
+}
+
+impl RpcConnection {
+    /// Whether the child is still running, used as the daemon's health check.
+    async fn is_alive(&self) -> bool {
+        matches!(self.child.lock().await.try_wait(), Ok(None))
+    }
 }
 
 pub struct CliIntegration {
     cli_path: PathBuf,
+    cli_source: CliSource,
     version_info: Option<VersionInfo>,
+    /// Schema version the handshake in `check_compatibility` settled on, or
+    /// `None` before that handshake has run.
+    negotiated_schema: Option<String>,
+    /// Optional features (`"progress"`, `"rpc"`, `"batch"`, ...) the CLI
+    /// advertised during the handshake. Empty until `check_compatibility`
+    /// runs, so every optional feature defaults to off rather than assumed.
+    capabilities: HashSet<String>,
+    /// The persistent RPC daemon, lazily spawned on first use.
+    rpc: RwLock<Option<Arc<RpcConnection>>>,
+    /// Set once spawning the RPC daemon has failed, so every subsequent call
+    /// goes straight to the one-shot fallback instead of retrying a CLI that
+    /// has already told us (by failing to start) it doesn't support `serve`.
+    rpc_unavailable: AtomicBool,
+}
+
+/// The target triple a Tauri sidecar binary is suffixed with, matching the
+/// convention used by `tauri build`'s `externalBin` resolution
+/// (`<name>-<target-triple>[.exe]`). `rustc -vV` would give us the exact
+/// triple at build time, but at runtime all we have is `std::env::consts`,
+/// so this covers the desktop triples TaxGlide actually ships for.
+fn host_target_triple() -> &'static str {
+    match (std::env::consts::ARCH, std::env::consts::OS) {
+        ("x86_64", "windows") => "x86_64-pc-windows-msvc",
+        ("aarch64", "windows") => "aarch64-pc-windows-msvc",
+        ("x86_64", "macos") => "x86_64-apple-darwin",
+        ("aarch64", "macos") => "aarch64-apple-darwin",
+        ("x86_64", "linux") => "x86_64-unknown-linux-gnu",
+        ("aarch64", "linux") => "aarch64-unknown-linux-gnu",
+        (arch, os) => {
+            warn!("Unrecognized target ({arch}, {os}) for sidecar resolution, falling back to a best-effort triple");
+            "unknown-unknown-unknown"
+        }
+    }
 }
 
 impl CliIntegration {
     /// Create a new CLI integration instance
     pub fn new() -> Result<Self, CliError> {
-        let cli_path = Self::discover_cli_executable()?;
-        info!("Found CLI executable at: {:?}", cli_path);
-        
+        let (cli_path, cli_source) = Self::discover_cli_executable()?;
+        info!("Found CLI executable at {:?} (source: {:?})", cli_path, cli_source);
+
         Ok(CliIntegration {
             cli_path,
+            cli_source,
             version_info: None,
+            negotiated_schema: None,
+            capabilities: HashSet::new(),
+            rpc: RwLock::new(None),
+            rpc_unavailable: AtomicBool::new(false),
         })
     }
-    
-    /// Discover the CLI executable path
-    /// In production: look for taxglide.exe in the same directory as GUI
-    /// In development: use python main.py in the parent directory with venv
-    fn discover_cli_executable() -> Result<PathBuf, CliError> {
+
+    /// Discover the CLI executable, preferring a bundled sidecar so a fresh
+    /// install just works on any OS, and only falling back to weaker options
+    /// when the sidecar isn't present (dev tree, or a system install on `PATH`).
+    fn discover_cli_executable() -> Result<(PathBuf, CliSource), CliError> {
         let current_exe = std::env::current_exe()
             .map_err(|e| CliError::ExecutableNotFound(format!("Cannot determine current executable: {}", e)))?;
-        
+
         let exe_dir = current_exe.parent()
             .ok_or_else(|| CliError::ExecutableNotFound("Cannot determine executable directory".to_string()))?;
-        
-        // First try: production executable (taxglide.exe in same directory)
-        let production_exe = exe_dir.join("taxglide.exe");
-        if production_exe.exists() {
-            info!("Found production CLI executable: {:?}", production_exe);
-            return Ok(production_exe);
+
+        // First try: the bundled sidecar. Tauri's `externalBin` mechanism
+        // copies it into the app bundle next to the main executable, named
+        // `<name>-<target-triple>` (plus the platform's exe suffix) so one
+        // bundle can ship binaries for every target; resolving by that same
+        // convention works identically on Windows/macOS/Linux, unlike the
+        // old hard-coded `taxglide.exe`.
+        let sidecar_exe = exe_dir.join(format!("taxglide-{}{}", host_target_triple(), std::env::consts::EXE_SUFFIX));
+        if sidecar_exe.exists() {
+            info!("Found bundled sidecar CLI: {:?}", sidecar_exe);
+            return Ok((sidecar_exe, CliSource::Sidecar));
         }
-        
+
         // Second try: development mode (main.py in parent directory)
         // GUI is in TaxGlide/gui/src-tauri/target/debug/, CLI is in TaxGlide/
         let dev_main_py = exe_dir
@@ -71,47 +310,70 @@ impl CliIntegration {
             .and_then(|p| p.parent()) // gui
             .and_then(|p| p.parent()) // TaxGlide
             .map(|p| p.join("main.py"));
-        
+
         if let Some(ref main_py) = dev_main_py {
             if main_py.exists() {
                 info!("Found development CLI script: {:?}", main_py);
-                return Ok(main_py.clone());
+                return Ok((main_py.clone(), CliSource::Dev));
             }
         }
-        
+
         // Third try: look for main.py in current directory (fallback)
         let current_main_py = exe_dir.join("main.py");
         if current_main_py.exists() {
             info!("Found CLI script in current directory: {:?}", current_main_py);
-            return Ok(current_main_py);
+            return Ok((current_main_py, CliSource::Dev));
         }
-        
+
+        // Last resort: a system install of the CLI on PATH, so a machine
+        // without the sidecar (or running against an independently managed
+        // CLI) still works, just without the bundled-version guarantee.
+        if let Some(system_path) = Self::find_on_path("taxglide") {
+            info!("Found system CLI on PATH: {:?}", system_path);
+            return Ok((system_path, CliSource::SystemPath));
+        }
+
         Err(CliError::ExecutableNotFound(format!(
-            "Cannot find CLI executable. Searched for: {:?}, {:?}", 
-            production_exe, 
+            "Cannot find CLI executable. Searched for: {:?}, {:?}, and \"taxglide\" on PATH",
+            sidecar_exe,
             dev_main_py.unwrap_or_else(|| PathBuf::from("main.py"))
         )))
     }
-    
-    /// Find the Python executable from virtual environment
-    /// Try to find python.exe in .venv/Scripts/ relative to the CLI script
+
+    /// Search `PATH` for an executable named `name` (with the platform's
+    /// executable suffix, e.g. `.exe` on Windows).
+    fn find_on_path(name: &str) -> Option<PathBuf> {
+        let path_var = std::env::var_os("PATH")?;
+        let exe_name = format!("{}{}", name, std::env::consts::EXE_SUFFIX);
+
+        std::env::split_paths(&path_var)
+            .map(|dir| dir.join(&exe_name))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Find the Python executable from a virtual environment next to the CLI
+    /// script, checking both the Windows (`Scripts/`) and Unix (`bin/`) venv
+    /// layouts since `main.py` discovery only ever runs in the dev tree,
+    /// which isn't restricted to one OS.
     fn find_venv_python(main_py_path: &PathBuf) -> Option<PathBuf> {
         let project_root = main_py_path.parent()?;
-        
-        // Check for .venv/Scripts/python.exe (Windows)
-        let venv_python = project_root.join(".venv").join("Scripts").join("python.exe");
-        if venv_python.exists() {
-            info!("Found virtual environment python: {:?}", venv_python);
-            return Some(venv_python);
-        }
-        
-        // Check for venv/Scripts/python.exe (alternate Windows location)
-        let venv_python_alt = project_root.join("venv").join("Scripts").join("python.exe");
-        if venv_python_alt.exists() {
-            info!("Found virtual environment python (venv): {:?}", venv_python_alt);
-            return Some(venv_python_alt);
+
+        let candidates = [
+            project_root.join(".venv").join("Scripts").join("python.exe"),
+            project_root.join("venv").join("Scripts").join("python.exe"),
+            project_root.join(".venv").join("bin").join("python3"),
+            project_root.join(".venv").join("bin").join("python"),
+            project_root.join("venv").join("bin").join("python3"),
+            project_root.join("venv").join("bin").join("python"),
+        ];
+
+        for candidate in candidates {
+            if candidate.exists() {
+                info!("Found virtual environment python: {:?}", candidate);
+                return Some(candidate);
+            }
         }
-        
+
         info!("No virtual environment python found, will use system python");
         None
     }
@@ -121,21 +383,46 @@ impl CliIntegration {
         debug!("Checking CLI compatibility...");
         
         let response: CliResponse<VersionInfo> = self
-            .execute_command(&["version", "--json", "--schema-version"], Duration::from_secs(10))
+            .execute_command(&["version", "--json", "--schema-version"], Duration::from_secs(10), None)
             .await?;
         
         match response.payload {
-            CliPayload::Success { data } => {
-                // Validate schema version
-                if data.schema_version != SCHEMA_VERSION {
-                    return Err(CliError::SchemaMismatch {
-                        expected: SCHEMA_VERSION.to_string(),
-                        actual: data.schema_version.clone(),
-                    });
+            CliPayload::Success { mut data } => {
+                // Negotiate a schema version instead of requiring an exact
+                // match: an older CLI that doesn't advertise min_schema/
+                // max_schema is treated as only speaking its single
+                // schema_version, same as before this handshake existed.
+                let cli_min_schema = if data.min_schema.is_empty() { data.schema_version.clone() } else { data.min_schema.clone() };
+                let cli_max_schema = if data.max_schema.is_empty() { data.schema_version.clone() } else { data.max_schema.clone() };
+
+                let negotiated_schema = negotiate_schema(&cli_min_schema, &cli_max_schema).map_err(|reason| {
+                    CliError::SchemaMismatch {
+                        expected: format!("{}..{}", MIN_SUPPORTED_SCHEMA_VERSION, MAX_SUPPORTED_SCHEMA_VERSION),
+                        actual: format!("{}..{} ({})", cli_min_schema, cli_max_schema, reason),
+                    }
+                })?;
+
+                // Negotiate against the GUI's declared app-version range: a
+                // CLI older than MIN_SUPPORTED_CLI_VERSION can't be trusted
+                // to behave as this GUI expects, so it hard-fails init_cli;
+                // a newer-than-tested CLI is accepted with a warning instead,
+                // so the GUI degrades gracefully rather than refusing to run.
+                let (compatibility, warning) = classify_compatibility(&data.version);
+                if compatibility == VersionCompatibility::TooOld {
+                    return Err(CliError::Incompatible(format!(
+                        "CLI version {} is older than the minimum supported {}. Please upgrade the TaxGlide CLI.",
+                        data.version, MIN_SUPPORTED_CLI_VERSION
+                    )));
                 }
-                
-                info!("CLI compatibility check passed: version {}, schema {}", 
-                      data.version, data.schema_version);
+                data.compatibility = compatibility;
+                data.warning = warning;
+
+                info!(
+                    "CLI compatibility check passed: version {}, negotiated schema {}, capabilities {:?}, compatibility {:?}",
+                    data.version, negotiated_schema, data.capabilities, data.compatibility
+                );
+                self.negotiated_schema = Some(negotiated_schema);
+                self.capabilities = data.capabilities.iter().cloned().collect();
                 self.version_info = Some(data.clone());
                 Ok(data)
             }
@@ -146,19 +433,16 @@ impl CliIntegration {
         }
     }
     
-    /// Execute a CLI command with timeout
-    async fn execute_command<T>(&self, args: &[&str], timeout_duration: Duration) 
-        -> Result<CliResponse<T>, CliError> 
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        debug!("Executing CLI command: {:?}", args);
-        
+    /// Build a `Command` that invokes the CLI with `args`, handling the
+    /// python-script-vs-executable distinction shared by every spawn path
+    /// (one-shot invocations and the RPC daemon alike). Stdio is left for
+    /// the caller to configure.
+    fn build_command(&self, args: &[&str]) -> Command {
         let mut command = if self.cli_path.extension() == Some(std::ffi::OsStr::new("py")) {
             // Python script - try to use virtual environment python, fallback to system python
             let python_exe = Self::find_venv_python(&self.cli_path)
                 .unwrap_or_else(|| PathBuf::from("python"));
-            
+
             let mut cmd = Command::new(python_exe);
             cmd.arg(&self.cli_path);
             cmd.args(args);
@@ -169,12 +453,7 @@ impl CliIntegration {
             cmd.args(args);
             cmd
         };
-        
-        command
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .stdin(Stdio::null());
-        
+
         // On Windows, hide the console window when spawning CLI process
         #[cfg(target_os = "windows")]
         {
@@ -182,10 +461,35 @@ impl CliIntegration {
             use std::os::windows::process::CommandExt;
             command.creation_flags(CREATE_NO_WINDOW);
         }
-        
-        let result = timeout(timeout_duration, async {
-            let mut child = command.spawn()?;
-            
+
+        command
+    }
+
+    /// Execute a CLI command with a timeout, killing the child and returning
+    /// `CliError::Cancelled` if `cancel` fires first (a newer request
+    /// superseding this one). Important on Windows given the `CREATE_NO_WINDOW`
+    /// spawn: there's no console for Ctrl+C, so `start_kill` is the only way
+    /// to stop the child promptly.
+    async fn execute_command<T>(
+        &self,
+        args: &[&str],
+        timeout_duration: Duration,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<CliResponse<T>, CliError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        debug!("Executing CLI command: {:?}", args);
+
+        let mut command = self.build_command(args);
+        command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::null());
+
+        let mut child = command.spawn()?;
+
+        let run = async {
             // Read stdout and stderr
             let stdout = child.stdout.take().ok_or_else(|| {
                 std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture stdout")
@@ -193,62 +497,196 @@ impl CliIntegration {
             let stderr = child.stderr.take().ok_or_else(|| {
                 std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture stderr")
             })?;
-            
+
             let mut stdout_reader = BufReader::new(stdout);
             let mut stderr_reader = BufReader::new(stderr);
-            
+
             let mut stdout_lines = Vec::new();
             let mut stderr_lines = Vec::new();
-            
+
             // Read all output
             let mut stdout_line = String::new();
             while stdout_reader.read_line(&mut stdout_line).await? > 0 {
                 stdout_lines.push(stdout_line.trim().to_string());
                 stdout_line.clear();
             }
-            
+
             let mut stderr_line = String::new();
             while stderr_reader.read_line(&mut stderr_line).await? > 0 {
                 stderr_lines.push(stderr_line.trim().to_string());
                 stderr_line.clear();
             }
-            
+
             let status = child.wait().await?;
-            
+
             Ok::<(Vec<String>, Vec<String>, std::process::ExitStatus), std::io::Error>((stdout_lines, stderr_lines, status))
-        }).await;
-        
-        let (stdout_lines, stderr_lines, status) = match result {
-            Ok(Ok(output)) => output,
-            Ok(Err(e)) => return Err(CliError::IoError(e)),
-            Err(_) => return Err(CliError::Timeout { 
-                seconds: timeout_duration.as_secs() 
-            }),
         };
-        
+
+        let cancelled = async {
+            match cancel {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+
+        let (stdout_lines, stderr_lines, status) = tokio::select! {
+            result = timeout(timeout_duration, run) => match result {
+                Ok(Ok(output)) => output,
+                Ok(Err(e)) => return Err(CliError::IoError(e)),
+                Err(_) => {
+                    warn!("CLI command timed out, killing child process: {:?}", args);
+                    let _ = child.start_kill();
+                    let _ = child.wait().await;
+                    return Err(CliError::Timeout { seconds: timeout_duration.as_secs() });
+                }
+            },
+            _ = cancelled => {
+                warn!("CLI command cancelled, killing child process: {:?}", args);
+                let _ = child.start_kill();
+                let _ = child.wait().await;
+                return Err(CliError::Cancelled);
+            }
+        };
+
         // Join stdout lines to get JSON response
         let stdout_text = stdout_lines.join("\n");
         
         if !status.success() {
-            let error_msg = if stderr_lines.is_empty() {
-                format!("CLI command failed with exit code: {}", status.code().unwrap_or(-1))
+            let code = status.code().unwrap_or(-1);
+            let stderr = if stderr_lines.is_empty() {
+                "(no stderr output)".to_string()
             } else {
                 stderr_lines.join("\n")
             };
-            
-            error!("CLI command failed: {}", error_msg);
-            return Err(CliError::ProcessFailed(error_msg));
+
+            error!("CLI command failed with code {}: {}", code, stderr);
+            return Err(CliError::ProcessFailed { code, stderr });
         }
-        
+
         if stdout_text.trim().is_empty() {
-            return Err(CliError::ProcessFailed("CLI returned empty output".to_string()));
+            return Err(CliError::ProcessFailed {
+                code: status.code().unwrap_or(-1),
+                stderr: "CLI returned empty output".to_string(),
+            });
         }
         
         debug!("CLI command succeeded, parsing JSON response");
         let response: CliResponse<T> = serde_json::from_str(&stdout_text)?;
         Ok(response)
     }
-    
+
+    /// Execute a CLI command run with `--progress`, where stdout is NDJSON
+    /// progress updates interleaved with a single terminating result line
+    /// (`{"kind":"progress",...}` / `{"kind":"result","data":...}`) rather
+    /// than one JSON blob. Progress updates are forwarded through `progress_tx`
+    /// as they arrive instead of being buffered, so a long `optimize`/`scan`
+    /// sweep can drive a live progress bar; the `result` line's `data` is
+    /// parsed into the typed return value exactly like `execute_command`.
+    /// Killed early (same `cancel`/Windows caveat as `execute_command`) if
+    /// `cancel` fires before a result line arrives.
+    async fn execute_command_with_progress<T>(
+        &self,
+        args: &[&str],
+        timeout_duration: Duration,
+        progress_tx: Option<mpsc::Sender<ProgressUpdate>>,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<CliResponse<T>, CliError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        debug!("Executing streaming CLI command: {:?}", args);
+
+        let mut command = self.build_command(args);
+        command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::null());
+
+        let mut child = command.spawn()?;
+
+        let stdout = child.stdout.take().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture stdout")
+        })?;
+        let stderr = child.stderr.take().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture stderr")
+        })?;
+
+        // Drain stderr on its own task; only stdout carries the protocol.
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                warn!("CLI stderr: {}", line);
+            }
+        });
+
+        let run = async {
+            let mut stdout_lines = BufReader::new(stdout).lines();
+            let mut result_line: Option<String> = None;
+
+            while let Some(line) = stdout_lines.next_line().await? {
+                match classify_progress_line(&line) {
+                    Some(ProgressLine::Progress(update)) => {
+                        if let Some(tx) = &progress_tx {
+                            let _ = tx.send(update).await;
+                        }
+                    }
+                    Some(ProgressLine::Result(data)) => {
+                        result_line = Some(data);
+                        break;
+                    }
+                    None => {}
+                }
+            }
+
+            let status = child.wait().await?;
+            Ok::<(Option<String>, std::process::ExitStatus), std::io::Error>((result_line, status))
+        };
+
+        let cancelled = async {
+            match cancel {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+
+        let (result_line, status) = tokio::select! {
+            result = timeout(timeout_duration, run) => match result {
+                Ok(Ok(output)) => output,
+                Ok(Err(e)) => return Err(CliError::IoError(e)),
+                Err(_) => {
+                    warn!("Streaming CLI command timed out, killing child process: {:?}", args);
+                    let _ = child.start_kill();
+                    let _ = child.wait().await;
+                    return Err(CliError::Timeout { seconds: timeout_duration.as_secs() });
+                }
+            },
+            _ = cancelled => {
+                warn!("Streaming CLI command cancelled, killing child process: {:?}", args);
+                let _ = child.start_kill();
+                let _ = child.wait().await;
+                return Err(CliError::Cancelled);
+            }
+        };
+
+        if !status.success() {
+            let code = status.code().unwrap_or(-1);
+            error!("Streaming CLI command failed with code {}", code);
+            return Err(CliError::ProcessFailed {
+                code,
+                stderr: "(see logs for stderr output)".to_string(),
+            });
+        }
+
+        let result_line = result_line.ok_or_else(|| CliError::ProcessFailed {
+            code: status.code().unwrap_or(-1),
+            stderr: "CLI exited without emitting a result line".to_string(),
+        })?;
+
+        debug!("Streaming CLI command succeeded, parsing result line");
+        let response: CliResponse<T> = serde_json::from_str(&result_line)?;
+        Ok(response)
+    }
+
     /// Build command arguments from parameters
     fn build_calc_args(&self, params: &CalcParams) -> Vec<String> {
         let mut args = vec![
@@ -425,9 +863,9 @@ impl CliIntegration {
         
         let args = self.build_calc_args(&params);
         let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        
+
         let response: CliResponse<CalcResult> = self
-            .execute_command(&args_str, Duration::from_secs(30))
+            .dispatch("calc", serde_json::to_value(&params)?, &args_str, Duration::from_secs(30), None)
             .await?;
         
         match response.payload {
@@ -439,21 +877,41 @@ impl CliIntegration {
         }
     }
     
-    pub async fn optimize(&self, params: OptimizeParams) -> Result<OptimizeResult, CliError> {
+    /// Optimize tax deductions. When `progress_tx` is given *and* the
+    /// handshake confirmed the CLI's `progress` capability, runs the CLI
+    /// with `--progress` and streams updates through it as the sweep runs,
+    /// bypassing the RPC daemon (which has no progress framing yet); if
+    /// either is missing, behaves exactly as before and goes through `dispatch`.
+    /// `cancel`, if given, aborts the sweep early (killing the one-shot
+    /// child, or abandoning the wait on an RPC call) and yields
+    /// `CliError::Cancelled`, letting a newer request supersede one still
+    /// in flight.
+    pub async fn optimize(
+        &self,
+        params: OptimizeParams,
+        progress_tx: Option<mpsc::Sender<ProgressUpdate>>,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<OptimizeResult, CliError> {
         // Validate parameters
         if params.income.is_none() && (params.income_sg.is_none() || params.income_fed.is_none()) {
             return Err(CliError::InvalidParameters(
                 "Must provide either income or both income_sg and income_fed".to_string()
             ));
         }
-        
-        let args = self.build_optimize_args(&params);
-        let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        
-        let response: CliResponse<OptimizeResult> = self
-            .execute_command(&args_str, Duration::from_secs(60))
-            .await?;
-        
+
+        let mut args = self.build_optimize_args(&params);
+        let progress_tx = progress_tx.filter(|_| self.has_capability("progress"));
+        let response: CliResponse<OptimizeResult> = if let Some(progress_tx) = progress_tx {
+            args.push("--progress".to_string());
+            let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+            self.execute_command_with_progress(&args_str, Duration::from_secs(60), Some(progress_tx), cancel)
+                .await?
+        } else {
+            let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+            self.dispatch("optimize", serde_json::to_value(&params)?, &args_str, Duration::from_secs(60), cancel)
+                .await?
+        };
+
         match response.payload {
             CliPayload::Success { data } => Ok(data),
             CliPayload::Error { error } => Err(CliError::CliError {
@@ -462,22 +920,35 @@ impl CliIntegration {
             }),
         }
     }
-    
-    pub async fn scan(&self, params: ScanParams) -> Result<ScanResult, CliError> {
+
+    /// Scan deduction ranges. Same progress-streaming/`dispatch`/`cancel`
+    /// split as [`CliIntegration::optimize`].
+    pub async fn scan(
+        &self,
+        params: ScanParams,
+        progress_tx: Option<mpsc::Sender<ProgressUpdate>>,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<ScanResult, CliError> {
         // Validate parameters
         if params.income.is_none() && (params.income_sg.is_none() || params.income_fed.is_none()) {
             return Err(CliError::InvalidParameters(
                 "Must provide either income or both income_sg and income_fed".to_string()
             ));
         }
-        
-        let args = self.build_scan_args(&params);
-        let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        
-        let response: CliResponse<ScanResult> = self
-            .execute_command(&args_str, Duration::from_secs(60))
-            .await?;
-        
+
+        let mut args = self.build_scan_args(&params);
+        let progress_tx = progress_tx.filter(|_| self.has_capability("progress"));
+        let response: CliResponse<ScanResult> = if let Some(progress_tx) = progress_tx {
+            args.push("--progress".to_string());
+            let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+            self.execute_command_with_progress(&args_str, Duration::from_secs(60), Some(progress_tx), cancel)
+                .await?
+        } else {
+            let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+            self.dispatch("scan", serde_json::to_value(&params)?, &args_str, Duration::from_secs(60), cancel)
+                .await?
+        };
+
         match response.payload {
             CliPayload::Success { data } => Ok(data),
             CliPayload::Error { error } => Err(CliError::CliError {
@@ -487,6 +958,60 @@ impl CliIntegration {
         }
     }
     
+    /// Default cap on in-flight CLI children for `calc_batch`/`scan_batch` —
+    /// high enough that a comparison chart over a few dozen income points
+    /// finishes quickly, low enough not to fork a Python process per point.
+    const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+    /// Hard ceiling on `calc_batch`/`scan_batch`'s `concurrency` override, so
+    /// a bad caller-supplied value (or a batch bigger than the override
+    /// anticipated) can't still spawn hundreds of CLI children at once.
+    const MAX_BATCH_CONCURRENCY: usize = 16;
+
+    /// Run many `calc` requests concurrently, each going through a regular
+    /// [`CliIntegration::calc`] call (the RPC daemon when available, else its
+    /// own one-shot CLI process), capped at
+    /// `concurrency` in flight at a time (default [`Self::DEFAULT_BATCH_CONCURRENCY`],
+    /// clamped to at most [`Self::MAX_BATCH_CONCURRENCY`] regardless of what's
+    /// requested). Input order is preserved in the returned `Vec`, and one
+    /// request failing doesn't abort the rest — each slot carries its own
+    /// `Result`, so building e.g. a marginal-rate chart over an income range
+    /// is one call instead of a sequential loop paying full process startup
+    /// each time.
+    pub async fn calc_batch(
+        &self,
+        params: Vec<CalcParams>,
+        concurrency: Option<usize>,
+    ) -> Vec<Result<CalcResult, CliError>> {
+        let concurrency = concurrency
+            .unwrap_or(Self::DEFAULT_BATCH_CONCURRENCY)
+            .clamp(1, Self::MAX_BATCH_CONCURRENCY);
+        stream::iter(params)
+            .map(|p| self.calc(p))
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
+    /// Same bounded, order-preserving batching as [`CliIntegration::calc_batch`]
+    /// (including the [`Self::MAX_BATCH_CONCURRENCY`] ceiling), for `scan`.
+    /// Runs without progress streaming or cancellation — those are per-call
+    /// concerns that don't map cleanly onto a whole batch.
+    pub async fn scan_batch(
+        &self,
+        params: Vec<ScanParams>,
+        concurrency: Option<usize>,
+    ) -> Vec<Result<ScanResult, CliError>> {
+        let concurrency = concurrency
+            .unwrap_or(Self::DEFAULT_BATCH_CONCURRENCY)
+            .clamp(1, Self::MAX_BATCH_CONCURRENCY);
+        stream::iter(params)
+            .map(|p| self.scan(p, None, None))
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
     pub async fn compare_brackets(&self, params: CompareBracketsParams) -> Result<CompareBracketsResult, CliError> {
         // Validate parameters
         if params.income.is_none() && (params.income_sg.is_none() || params.income_fed.is_none()) {
@@ -497,9 +1022,9 @@ impl CliIntegration {
         
         let args = self.build_compare_brackets_args(&params);
         let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        
+
         let response: CliResponse<CompareBracketsResult> = self
-            .execute_command(&args_str, Duration::from_secs(30))
+            .dispatch("compare-brackets", serde_json::to_value(&params)?, &args_str, Duration::from_secs(30), None)
             .await?;
         
         match response.payload {
@@ -514,9 +1039,9 @@ impl CliIntegration {
     pub async fn validate(&self, params: ValidateParams) -> Result<ValidateResult, CliError> {
         let args = self.build_validate_args(&params);
         let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        
+
         let response: CliResponse<ValidateResult> = self
-            .execute_command(&args_str, Duration::from_secs(30))
+            .dispatch("validate", serde_json::to_value(&params)?, &args_str, Duration::from_secs(30), None)
             .await?;
         
         match response.payload {
@@ -528,8 +1053,232 @@ impl CliIntegration {
         }
     }
     
+    /// Spawn the `serve --rpc` daemon and start its reader/writer background
+    /// tasks. The stdout reader demultiplexes NDJSON response lines
+    /// (`{"id": <u64>, "payload": <CliResponse<T> as Value>}`) to whichever
+    /// caller is awaiting that `id`. A line that fails to parse is logged and
+    /// skipped; stdout closing fails every still-pending call so nothing
+    /// hangs forever.
+    fn spawn_rpc_daemon(&self) -> Result<Arc<RpcConnection>, CliError> {
+        let mut command = self.build_command(&["serve", "--rpc"]);
+        command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::piped())
+            // Unlike the one-shot paths, this child outlives the call that
+            // spawned it, so it's never explicitly waited on. Without this,
+            // replacing `self.rpc` (re-init) or dropping the `CliIntegration`
+            // entirely would orphan the daemon as a running background process.
+            .kill_on_drop(true);
+
+        let mut child = command.spawn()?;
+        let stdin = child.stdin.take().ok_or_else(|| {
+            CliError::IoError(std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture RPC stdin"))
+        })?;
+        let stdout = child.stdout.take().ok_or_else(|| {
+            CliError::IoError(std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture RPC stdout"))
+        })?;
+        let stderr = child.stderr.take().ok_or_else(|| {
+            CliError::IoError(std::io::Error::new(std::io::ErrorKind::Other, "Failed to capture RPC stderr"))
+        })?;
+
+        let pending: PendingCalls = Arc::new(AsyncMutex::new(HashMap::new()));
+
+        // Log the daemon's stderr as it arrives rather than buffering it,
+        // since this process may outlive any single RPC call.
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                warn!("RPC daemon stderr: {}", line);
+            }
+        });
+
+        let reader_pending = pending.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            loop {
+                match lines.next_line().await {
+                    Ok(Some(line)) => {
+                        #[derive(serde::Deserialize)]
+                        struct Envelope {
+                            id: u64,
+                            payload: serde_json::Value,
+                        }
+                        match serde_json::from_str::<Envelope>(&line) {
+                            Ok(envelope) => {
+                                if let Some(sender) = reader_pending.lock().await.remove(&envelope.id) {
+                                    let _ = sender.send(Ok(envelope.payload));
+                                }
+                            }
+                            Err(e) => warn!("RPC daemon sent unparseable line: {} ({})", line, e),
+                        }
+                    }
+                    Ok(None) | Err(_) => break,
+                }
+            }
+            // stdout closed: the daemon is gone, fail every call still waiting.
+            let mut pending = reader_pending.lock().await;
+            for (_, sender) in pending.drain() {
+                let _ = sender.send(Err("RPC daemon connection closed".to_string()));
+            }
+        });
+
+        Ok(Arc::new(RpcConnection {
+            child: AsyncMutex::new(child),
+            stdin: AsyncMutex::new(stdin),
+            pending,
+            next_id: AtomicU64::new(1),
+        }))
+    }
+
+    /// Return the live RPC connection, (re)spawning it if there isn't one or
+    /// the previous child has died, unless a prior spawn attempt already
+    /// marked RPC unavailable, or the handshake never advertised the `rpc`
+    /// capability in the first place (an older CLI that may not even
+    /// understand `serve --rpc`).
+    async fn ensure_rpc(&self) -> Option<Arc<RpcConnection>> {
+        if self.rpc_unavailable.load(Ordering::Relaxed) || !self.has_capability("rpc") {
+            return None;
+        }
+
+        if let Some(conn) = self.rpc.read().await.as_ref() {
+            if conn.is_alive().await {
+                return Some(conn.clone());
+            }
+        }
+
+        let mut slot = self.rpc.write().await;
+        if let Some(conn) = slot.as_ref() {
+            if conn.is_alive().await {
+                return Some(conn.clone());
+            }
+        }
+
+        match self.spawn_rpc_daemon() {
+            Ok(conn) => {
+                info!("Spawned RPC daemon");
+                *slot = Some(conn.clone());
+                Some(conn)
+            }
+            Err(e) => {
+                warn!("Failed to spawn RPC daemon, disabling RPC for this session: {}", e);
+                self.rpc_unavailable.store(true, Ordering::Relaxed);
+                *slot = None;
+                None
+            }
+        }
+    }
+
+    /// Issue one request over an established RPC connection and await its
+    /// matching response, or the daemon's failure, via the caller's oneshot.
+    /// If `cancel` fires first, the wait is abandoned and `CliError::Cancelled`
+    /// is returned; the daemon itself (shared with future calls) is left
+    /// running rather than killed.
+    async fn execute_rpc<T>(
+        &self,
+        conn: &Arc<RpcConnection>,
+        method: &str,
+        params: serde_json::Value,
+        timeout_duration: Duration,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<CliResponse<T>, CliError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let id = conn.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = oneshot::channel();
+        conn.pending.lock().await.insert(id, tx);
+
+        let request = serde_json::json!({ "id": id, "method": method, "params": params });
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+
+        if let Err(e) = conn.stdin.lock().await.write_all(line.as_bytes()).await {
+            conn.pending.lock().await.remove(&id);
+            return Err(CliError::IoError(e));
+        }
+
+        let cancelled = async {
+            match cancel {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+
+        let payload = tokio::select! {
+            result = timeout(timeout_duration, rx) => match result {
+                Ok(Ok(Ok(payload))) => payload,
+                Ok(Ok(Err(reason))) => {
+                    return Err(CliError::IoError(std::io::Error::new(std::io::ErrorKind::BrokenPipe, reason)))
+                }
+                Ok(Err(_)) => {
+                    return Err(CliError::IoError(std::io::Error::new(
+                        std::io::ErrorKind::BrokenPipe,
+                        "RPC daemon dropped the request",
+                    )))
+                }
+                Err(_) => {
+                    conn.pending.lock().await.remove(&id);
+                    return Err(CliError::Timeout { seconds: timeout_duration.as_secs() });
+                }
+            },
+            _ = cancelled => {
+                conn.pending.lock().await.remove(&id);
+                return Err(CliError::Cancelled);
+            }
+        };
+
+        Ok(serde_json::from_value(payload)?)
+    }
+
+    /// Run a command through the persistent RPC daemon when available,
+    /// transparently falling back to a one-shot `execute_command` spawn
+    /// (using `args`) when RPC isn't available or the call over it fails.
+    /// A crashed daemon is simply re-spawned by `ensure_rpc` on the next call.
+    /// `cancel`, if given, aborts either path and kills the one-shot child.
+    async fn dispatch<T>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        args: &[&str],
+        timeout_duration: Duration,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<CliResponse<T>, CliError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if let Some(conn) = self.ensure_rpc().await {
+            match self.execute_rpc(&conn, method, params, timeout_duration, cancel).await {
+                Ok(response) => return Ok(response),
+                Err(CliError::Cancelled) => return Err(CliError::Cancelled),
+                Err(e) => {
+                    warn!("RPC call to '{}' failed, falling back to one-shot invocation: {}", method, e);
+                }
+            }
+        }
+
+        self.execute_command(args, timeout_duration, cancel).await
+    }
+
     /// Get version information (cached after first compatibility check)
     pub fn get_version_info(&self) -> Option<&VersionInfo> {
         self.version_info.as_ref()
     }
+
+    /// Where the CLI executable backing this integration was resolved from.
+    pub fn get_cli_source(&self) -> &CliSource {
+        &self.cli_source
+    }
+
+    /// The schema version `check_compatibility`'s handshake settled on, or
+    /// `None` before that handshake has run.
+    pub fn negotiated_schema(&self) -> Option<&str> {
+        self.negotiated_schema.as_deref()
+    }
+
+    /// Whether the CLI advertised `name` in its handshake `capabilities`.
+    /// Always `false` before `check_compatibility` has run.
+    fn has_capability(&self, name: &str) -> bool {
+        self.capabilities.contains(name)
+    }
 }
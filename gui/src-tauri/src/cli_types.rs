@@ -1,6 +1,116 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+/// Machine-readable error returned from every `#[tauri::command]`, in place
+/// of a bare `String`. Every variant carries a stable `code` the frontend can
+/// switch on, a human-readable `message`, and a `retryable` flag so the UI
+/// can decide whether to offer a retry button instead of just showing a toast.
+#[derive(Debug, Clone)]
+pub enum TaxGlideError {
+    /// `init_cli` hasn't succeeded yet, so no command can reach the CLI.
+    NotInitialized,
+    /// A GUI-side input failed validation before it was ever sent to the CLI,
+    /// or (with `field` `"cli_version"`) the CLI's version is below the
+    /// supported range.
+    Validation { field: String, reason: String },
+    /// The CLI process could not be spawned (missing executable, IO error).
+    CliSpawn(String),
+    /// The CLI process ran but exited with a non-zero status. Also carries
+    /// errors the CLI reported in its JSON response and schema mismatches,
+    /// with `code` set to -1.
+    CliNonZeroExit { code: i32, stderr: String },
+    /// The CLI's stdout could not be parsed as the expected JSON shape.
+    Deserialize(String),
+    /// The CLI did not respond within the command's timeout.
+    Timeout { seconds: u64 },
+    /// The command was superseded by a newer request before it finished: a
+    /// one-shot CLI child process is killed, while a shared RPC daemon is
+    /// left running and only the wait for its response is abandoned.
+    Cancelled,
+}
+
+impl TaxGlideError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaxGlideError::NotInitialized => "not_initialized",
+            TaxGlideError::Validation { .. } => "validation",
+            TaxGlideError::CliSpawn(_) => "cli_spawn",
+            TaxGlideError::CliNonZeroExit { .. } => "cli_non_zero_exit",
+            TaxGlideError::Deserialize(_) => "deserialize",
+            TaxGlideError::Timeout { .. } => "timeout",
+            TaxGlideError::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            TaxGlideError::NotInitialized => {
+                "CLI not initialized. Call init_cli first.".to_string()
+            }
+            TaxGlideError::Validation { field, reason } => format!("{}: {}", field, reason),
+            TaxGlideError::CliSpawn(reason) => format!("Failed to start CLI process: {}", reason),
+            TaxGlideError::CliNonZeroExit { code, stderr } => {
+                format!("CLI exited with code {}: {}", code, stderr)
+            }
+            TaxGlideError::Deserialize(reason) => {
+                format!("Failed to parse CLI response: {}", reason)
+            }
+            TaxGlideError::Timeout { seconds } => {
+                format!("CLI command timed out after {}s", seconds)
+            }
+            TaxGlideError::Cancelled => {
+                "Cancelled: superseded by a newer request".to_string()
+            }
+        }
+    }
+
+    pub fn retryable(&self) -> bool {
+        matches!(
+            self,
+            TaxGlideError::CliSpawn(_)
+                | TaxGlideError::Timeout { .. }
+                | TaxGlideError::CliNonZeroExit { .. }
+        )
+    }
+}
+
+impl std::fmt::Display for TaxGlideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for TaxGlideError {}
+
+impl Serialize for TaxGlideError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Wire<'a> {
+            code: &'a str,
+            message: String,
+            retryable: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            field: Option<&'a str>,
+        }
+
+        let field = match self {
+            TaxGlideError::Validation { field, .. } => Some(field.as_str()),
+            _ => None,
+        };
+
+        Wire {
+            code: self.code(),
+            message: self.message(),
+            retryable: self.retryable(),
+            field,
+        }
+        .serialize(serializer)
+    }
+}
+
 /// Schema version for CLI-GUI contract compatibility
 pub const SCHEMA_VERSION: &str = "1.0";
 
@@ -28,13 +138,157 @@ pub struct CliError {
     pub details: Option<serde_json::Value>,
 }
 
+/// Where the CLI executable this GUI is talking to was resolved from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CliSource {
+    /// Bundled as a Tauri sidecar resource next to the GUI binary.
+    Sidecar,
+    /// A `main.py` dev checkout found relative to the GUI binary.
+    Dev,
+    /// Found on the user's `PATH`, independent of this GUI's install.
+    SystemPath,
+}
+
+/// Lowest CLI app version this GUI declares support for.
+pub const MIN_SUPPORTED_CLI_VERSION: &str = "1.0.0";
+/// Highest CLI app version this GUI has been tested against.
+pub const MAX_SUPPORTED_CLI_VERSION: &str = "1.99.99";
+
+/// Result of comparing a CLI's reported version against the GUI's declared
+/// `MIN_SUPPORTED_CLI_VERSION..=MAX_SUPPORTED_CLI_VERSION` range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionCompatibility {
+    /// Within the declared range.
+    Supported,
+    /// Below `MIN_SUPPORTED_CLI_VERSION`; `init_cli` hard-fails.
+    TooOld,
+    /// Above `MAX_SUPPORTED_CLI_VERSION`; accepted, but with a warning.
+    TooNew,
+    /// The CLI's version string didn't parse as `major.minor.patch`.
+    Unparseable,
+}
+
+impl Default for VersionCompatibility {
+    fn default() -> Self {
+        VersionCompatibility::Unparseable
+    }
+}
+
+/// Parses a `major.minor.patch` prefix out of a version string, ignoring any
+/// trailing pre-release/build metadata (e.g. `1.2.3-beta.1` -> `(1, 2, 3)`).
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split(['-', '+']).next().unwrap_or(version);
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+    Some((major, minor, patch))
+}
+
+/// Classifies `cli_version` against this GUI's supported range, returning an
+/// optional human-readable warning for the `TooNew` and `Unparseable` cases.
+pub fn classify_compatibility(cli_version: &str) -> (VersionCompatibility, Option<String>) {
+    let Some(version) = parse_semver(cli_version) else {
+        return (
+            VersionCompatibility::Unparseable,
+            Some(format!(
+                "Could not parse CLI version '{}' as semver; compatibility is unknown.",
+                cli_version
+            )),
+        );
+    };
+
+    let min = parse_semver(MIN_SUPPORTED_CLI_VERSION).expect("MIN_SUPPORTED_CLI_VERSION is valid semver");
+    let max = parse_semver(MAX_SUPPORTED_CLI_VERSION).expect("MAX_SUPPORTED_CLI_VERSION is valid semver");
+
+    if version < min {
+        (VersionCompatibility::TooOld, None)
+    } else if version > max {
+        (
+            VersionCompatibility::TooNew,
+            Some(format!(
+                "CLI {} is newer than tested {}; some features may misbehave.",
+                cli_version, MAX_SUPPORTED_CLI_VERSION
+            )),
+        )
+    } else {
+        (VersionCompatibility::Supported, None)
+    }
+}
+
+/// Range of wire schema versions this GUI can speak. Widens over time as the
+/// GUI adds support for older/newer schemas instead of requiring an exact
+/// match against a single `SCHEMA_VERSION`.
+pub const MIN_SUPPORTED_SCHEMA_VERSION: &str = "1.0";
+pub const MAX_SUPPORTED_SCHEMA_VERSION: &str = "1.0";
+
+/// Parses a `major.minor` schema version string like `"1.0"` or `"1.12"`.
+fn parse_schema_version(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Negotiates a schema version between this GUI's supported range
+/// (`MIN_SUPPORTED_SCHEMA_VERSION..=MAX_SUPPORTED_SCHEMA_VERSION`) and the
+/// CLI's advertised `[cli_min, cli_max]`, picking the highest version both
+/// sides support. `Err` (with both ranges described, for diagnosability)
+/// only when the ranges genuinely don't overlap or don't parse.
+pub fn negotiate_schema(cli_min: &str, cli_max: &str) -> Result<String, String> {
+    let gui_min = parse_schema_version(MIN_SUPPORTED_SCHEMA_VERSION)
+        .expect("MIN_SUPPORTED_SCHEMA_VERSION is valid major.minor");
+    let gui_max = parse_schema_version(MAX_SUPPORTED_SCHEMA_VERSION)
+        .expect("MAX_SUPPORTED_SCHEMA_VERSION is valid major.minor");
+
+    let (Some(cli_min_v), Some(cli_max_v)) = (parse_schema_version(cli_min), parse_schema_version(cli_max)) else {
+        return Err(format!(
+            "GUI supports schema {}..{}, but could not parse CLI schema range '{}..{}'",
+            MIN_SUPPORTED_SCHEMA_VERSION, MAX_SUPPORTED_SCHEMA_VERSION, cli_min, cli_max
+        ));
+    };
+
+    let overlap_min = gui_min.max(cli_min_v);
+    let overlap_max = gui_max.min(cli_max_v);
+
+    if overlap_min > overlap_max {
+        return Err(format!(
+            "GUI supports schema {}..{}, CLI supports {}..{} — ranges do not overlap",
+            MIN_SUPPORTED_SCHEMA_VERSION, MAX_SUPPORTED_SCHEMA_VERSION, cli_min, cli_max
+        ));
+    }
+
+    Ok(format!("{}.{}", overlap_max.0, overlap_max.1))
+}
+
 /// Version information response
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct VersionInfo {
     pub version: String,
     pub platform: String,
     pub schema_version: String,
+    /// Lowest/highest wire schema version this CLI can speak. Older CLIs
+    /// that don't advertise a range leave these empty; `check_compatibility`
+    /// then treats `schema_version` itself as the (single-version) range.
+    #[serde(default)]
+    pub min_schema: String,
+    #[serde(default)]
+    pub max_schema: String,
+    /// Optional feature flags the CLI supports (e.g. `"progress"`, `"rpc"`,
+    /// `"batch"`), so the GUI only uses a feature the handshake confirmed
+    /// rather than assuming every CLI build has it.
+    #[serde(default)]
+    pub capabilities: Vec<String>,
     pub build_date: DateTime<Utc>,
+    /// Where `version` falls relative to this GUI's supported CLI range.
+    /// Filled in by `check_compatibility`; absent from the CLI's own response.
+    #[serde(default)]
+    pub compatibility: VersionCompatibility,
+    /// Set when `compatibility` is `TooNew` or `Unparseable`, for display to the user.
+    #[serde(default)]
+    pub warning: Option<String>,
 }
 
 /// Tax calculation result
@@ -237,6 +491,16 @@ pub struct ValidateResult {
     pub message: String,
 }
 
+/// One progress update emitted by a CLI command run with `--progress`
+/// (currently `optimize` and `scan`), relayed to the frontend as a Tauri
+/// event so a long sweep can show a live progress bar and partial best guess.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProgressUpdate {
+    pub done: u32,
+    pub total: u32,
+    pub best_so_far: Option<serde_json::Value>,
+}
+
 /// Input parameters for CLI commands
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CalcParams {
@@ -567,3 +831,83 @@ pub struct CantonDetailsResult {
     pub rounding: RoundingConfig,
     pub municipalities: std::collections::HashMap<String, MunicipalityConfig>,
 }
+
+#[cfg(test)]
+mod compatibility_tests {
+    use super::*;
+
+    #[test]
+    fn supported_within_range() {
+        assert_eq!(classify_compatibility("1.5.0").0, VersionCompatibility::Supported);
+    }
+
+    #[test]
+    fn min_bound_is_supported() {
+        assert_eq!(classify_compatibility(MIN_SUPPORTED_CLI_VERSION).0, VersionCompatibility::Supported);
+    }
+
+    #[test]
+    fn max_bound_is_supported() {
+        assert_eq!(classify_compatibility(MAX_SUPPORTED_CLI_VERSION).0, VersionCompatibility::Supported);
+    }
+
+    #[test]
+    fn below_min_is_too_old() {
+        let (compat, warning) = classify_compatibility("0.9.9");
+        assert_eq!(compat, VersionCompatibility::TooOld);
+        assert!(warning.is_none());
+    }
+
+    #[test]
+    fn above_max_is_too_new_with_warning() {
+        let (compat, warning) = classify_compatibility("2.0.0");
+        assert_eq!(compat, VersionCompatibility::TooNew);
+        assert!(warning.is_some());
+    }
+
+    #[test]
+    fn unparseable_version_is_flagged() {
+        let (compat, warning) = classify_compatibility("not-a-version");
+        assert_eq!(compat, VersionCompatibility::Unparseable);
+        assert!(warning.is_some());
+    }
+
+    #[test]
+    fn prerelease_metadata_is_ignored() {
+        assert_eq!(classify_compatibility("1.5.0-beta.1").0, VersionCompatibility::Supported);
+    }
+}
+
+#[cfg(test)]
+mod schema_negotiation_tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_picks_that_version() {
+        assert_eq!(negotiate_schema("1.0", "1.0").unwrap(), "1.0");
+    }
+
+    #[test]
+    fn picks_highest_version_in_the_overlap() {
+        assert_eq!(negotiate_schema("0.5", "1.0").unwrap(), "1.0");
+    }
+
+    #[test]
+    fn equal_min_and_max_on_both_sides_overlap_at_a_point() {
+        assert_eq!(negotiate_schema("1.0", "1.0").unwrap(), MIN_SUPPORTED_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn non_overlapping_ranges_are_rejected() {
+        let result = negotiate_schema("2.0", "3.0");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("do not overlap"));
+    }
+
+    #[test]
+    fn unparseable_cli_range_is_rejected() {
+        let result = negotiate_schema("not-a-version", "also-not-one");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("could not parse"));
+    }
+}
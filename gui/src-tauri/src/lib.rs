@@ -2,6 +2,7 @@
 
 mod cli_integration;
 mod cli_types;
+mod numeric_input;
 mod tauri_commands;
 
 use tauri_commands::CliState;
@@ -27,8 +28,10 @@ pub fn run() {
             tauri_commands::init_cli,
             tauri_commands::get_version_info,
             tauri_commands::calc,
+            tauri_commands::calc_batch,
             tauri_commands::optimize,
             tauri_commands::scan,
+            tauri_commands::scan_batch,
             tauri_commands::compare_brackets,
             tauri_commands::validate_config,
             tauri_commands::is_cli_ready,
@@ -46,6 +49,7 @@ pub fn run() {
             tauri_commands::update_municipality,
             tauri_commands::get_federal_segments,
             tauri_commands::cli_get_canton,
+            tauri_commands::parse_chf_amount_input,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,140 @@
+use crate::cli_types::TaxGlideError;
+
+/// Upper bound shared by every CHF amount field in the GUI: implausibly
+/// large, but comfortably inside `i32` range.
+pub const MAX_REASONABLE_CHF: i64 = 2_000_000_000;
+
+/// Bounds every CHF amount field must satisfy, regardless of whether the
+/// value arrived as a raw `i32` param or was round-tripped through
+/// `parse_chf_amount`. Centralizes the non-negative/overflow checks that
+/// used to be duplicated across `validate_income_params` and
+/// `validate_deduction_params`.
+pub fn check_amount_bounds(field: &str, value: i32) -> Result<(), TaxGlideError> {
+    if value < 0 {
+        return Err(TaxGlideError::Validation {
+            field: field.to_string(),
+            reason: "Amount cannot be negative. Even tax authorities aren't that generous!".to_string(),
+        });
+    }
+    if i64::from(value) > MAX_REASONABLE_CHF {
+        return Err(TaxGlideError::Validation {
+            field: field.to_string(),
+            reason: "🤑 You're too freaking rich, just pay your taxes! (Amount exceeds system limits)".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Parses a Swiss-locale CHF amount as typed by a user — `"120'000"`,
+/// `"120 000"`, `"120,000.00 CHF"` — into an `i32`.
+///
+/// Apostrophe, space, and comma are treated as thousands separators and
+/// stripped; a trailing currency marker (e.g. `"CHF"`) and surrounding
+/// whitespace are tolerated; a fractional franc amount is rounded to the
+/// nearest integer. Non-numeric input, and the same non-negative/overflow
+/// bounds every amount field enforces, are rejected via
+/// `TaxGlideError::Validation` naming `field`.
+pub fn parse_chf_amount(field: &str, input: &str) -> Result<i32, TaxGlideError> {
+    let invalid = || TaxGlideError::Validation {
+        field: field.to_string(),
+        reason: format!("'{}' is not a valid amount.", input.trim()),
+    };
+
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(TaxGlideError::Validation {
+            field: field.to_string(),
+            reason: "Amount cannot be empty.".to_string(),
+        });
+    }
+
+    // Drop a trailing currency marker ("CHF", "chf", ...) and the whitespace
+    // around it; a decimal point is never alphabetic, so it's untouched.
+    let without_currency = trimmed
+        .trim_end_matches(|c: char| c.is_whitespace() || c.is_alphabetic())
+        .trim();
+
+    // Strip thousands separators. Swiss formatting uses an apostrophe
+    // ("120'000"), but a plain space or comma shows up too depending on
+    // locale/input method.
+    let digits: String = without_currency
+        .chars()
+        .filter(|c| !matches!(c, '\'' | ',' | '\u{a0}') && !c.is_whitespace())
+        .collect();
+
+    let value: f64 = digits.parse().map_err(|_| invalid())?;
+    if !value.is_finite() {
+        return Err(invalid());
+    }
+
+    let rounded = value.round();
+    if rounded < i32::MIN as f64 || rounded > i32::MAX as f64 {
+        return Err(invalid());
+    }
+
+    let amount = rounded as i32;
+    check_amount_bounds(field, amount)?;
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod parse_chf_amount_tests {
+    use super::*;
+
+    #[test]
+    fn plain_integer() {
+        assert_eq!(parse_chf_amount("income", "120000").unwrap(), 120_000);
+    }
+
+    #[test]
+    fn apostrophe_thousands_separator() {
+        assert_eq!(parse_chf_amount("income", "120'000").unwrap(), 120_000);
+    }
+
+    #[test]
+    fn space_thousands_separator() {
+        assert_eq!(parse_chf_amount("income", "120 000").unwrap(), 120_000);
+    }
+
+    #[test]
+    fn comma_thousands_separator_with_currency_marker() {
+        assert_eq!(parse_chf_amount("income", "120,000.00 CHF").unwrap(), 120_000);
+    }
+
+    #[test]
+    fn fractional_amount_rounds_to_nearest() {
+        assert_eq!(parse_chf_amount("income", "120'000.6").unwrap(), 120_001);
+    }
+
+    #[test]
+    fn empty_input_is_rejected() {
+        let err = parse_chf_amount("income", "   ").unwrap_err();
+        assert_eq!(err.code(), "validation");
+    }
+
+    #[test]
+    fn non_numeric_input_is_rejected() {
+        let err = parse_chf_amount("income", "not an amount").unwrap_err();
+        assert_eq!(err.code(), "validation");
+    }
+
+    #[test]
+    fn negative_amount_is_rejected() {
+        let err = parse_chf_amount("income", "-100").unwrap_err();
+        assert_eq!(err.code(), "validation");
+    }
+
+    #[test]
+    fn amount_over_the_reasonable_bound_is_rejected() {
+        let err = parse_chf_amount("income", "3'000'000'000").unwrap_err();
+        assert_eq!(err.code(), "validation");
+    }
+
+    #[test]
+    fn amount_at_the_reasonable_bound_is_accepted() {
+        assert_eq!(
+            parse_chf_amount("income", &MAX_REASONABLE_CHF.to_string()).unwrap(),
+            MAX_REASONABLE_CHF as i32
+        );
+    }
+}
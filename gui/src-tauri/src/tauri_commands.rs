@@ -1,51 +1,103 @@
-use crate::cli_integration::CliIntegration;
+use crate::cli_integration::{retry, CliIntegration, RetryConfig};
 use crate::cli_types::*;
+use crate::numeric_input::{check_amount_bounds, parse_chf_amount};
 use log::{error, info};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tauri::State;
-use tokio::sync::RwLock;
+use tauri::{Emitter, State, Window};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
 
 /// Global state for CLI integration
 pub struct CliState {
     pub cli: Arc<RwLock<Option<CliIntegration>>>,
+    /// Backoff policy for cheap read-only commands (calc/validate/...).
+    pub read_retry: RetryConfig,
+    /// Backoff policy for commands that mutate config on disk.
+    pub write_retry: RetryConfig,
+    /// Backoff policy for long-running sweeps (optimize/scan): no retries,
+    /// since a timeout there is likely genuine rather than transient.
+    pub long_running_retry: RetryConfig,
+    /// Cancellation handle for the most recently started `optimize` call, so
+    /// a new request supersedes (cancels) one still in flight.
+    optimize_inflight: Inflight,
+    /// Same as `optimize_inflight`, for `scan`.
+    scan_inflight: Inflight,
 }
 
 impl CliState {
     pub fn new() -> Self {
         Self {
             cli: Arc::new(RwLock::new(None)),
+            read_retry: RetryConfig::read_only(),
+            write_retry: RetryConfig::mutating(),
+            long_running_retry: RetryConfig::long_running(),
+            optimize_inflight: Inflight::default(),
+            scan_inflight: Inflight::default(),
+        }
+    }
+}
+
+/// Tracks the cancellation token of whichever request is currently running,
+/// tagged with a generation id so a finishing call only clears the slot if a
+/// newer request hasn't already taken it over.
+#[derive(Default)]
+struct Inflight {
+    slot: Mutex<Option<(u64, CancellationToken)>>,
+    next_id: AtomicU64,
+}
+
+impl Inflight {
+    /// Cancel whatever request this slot is currently tracking (if any) and
+    /// register a fresh token for the caller's new request.
+    async fn start(&self) -> (u64, CancellationToken) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let token = CancellationToken::new();
+        let mut slot = self.slot.lock().await;
+        if let Some((_, prev)) = slot.replace((id, token.clone())) {
+            prev.cancel();
+        }
+        (id, token)
+    }
+
+    /// Clear the slot, but only if it still tracks `id` — a later request may
+    /// already have superseded it.
+    async fn finish(&self, id: u64) {
+        let mut slot = self.slot.lock().await;
+        if matches!(slot.as_ref(), Some((current, _)) if *current == id) {
+            *slot = None;
         }
     }
 }
 
 /// Initialize CLI integration and check compatibility
 #[tauri::command]
-pub async fn init_cli(state: State<'_, CliState>) -> Result<VersionInfo, String> {
+pub async fn init_cli(state: State<'_, CliState>) -> Result<VersionInfo, TaxGlideError> {
     info!("Initializing CLI integration...");
-    
-    let mut cli_integration = CliIntegration::new()
-        .map_err(|e| format!("Failed to create CLI integration: {}", e))?;
-    
-    let version_info = cli_integration
-        .check_compatibility()
-        .await
-        .map_err(|e| format!("CLI compatibility check failed: {}", e))?;
-    
+
+    let (cli_integration, version_info) = retry(&state.write_retry, || async {
+        let mut cli_integration = CliIntegration::new()?;
+        let version_info = cli_integration.check_compatibility().await?;
+        Ok((cli_integration, version_info))
+    })
+    .await
+    .map_err(TaxGlideError::from)?;
+
     // Store the CLI integration in state
     {
         let mut cli_lock = state.cli.write().await;
         *cli_lock = Some(cli_integration);
     }
-    
+
     info!("CLI integration initialized successfully");
     Ok(version_info)
 }
 
 /// Get CLI version information (if already initialized)
 #[tauri::command]
-pub async fn get_version_info(state: State<'_, CliState>) -> Result<Option<VersionInfo>, String> {
+pub async fn get_version_info(state: State<'_, CliState>) -> Result<Option<VersionInfo>, TaxGlideError> {
     let cli_lock = state.cli.read().await;
-    
+
     match cli_lock.as_ref() {
         Some(cli) => Ok(cli.get_version_info().cloned()),
         None => Ok(None),
@@ -54,154 +106,227 @@ pub async fn get_version_info(state: State<'_, CliState>) -> Result<Option<Versi
 
 /// Calculate taxes
 #[tauri::command]
-pub async fn calc(state: State<'_, CliState>, params: CalcParams) -> Result<CalcResult, String> {
+pub async fn calc(state: State<'_, CliState>, params: CalcParams) -> Result<CalcResult, TaxGlideError> {
     info!("Processing calc command: {:?}", params);
-    
+
     // Validate parameters to prevent integer overflow
     validate_income_params(params.income, params.income_sg, params.income_fed)?;
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.calc(params).await.map_err(|e| {
-        error!("Calc command failed: {}", e);
-        format!("Calculation failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.read_retry, || cli.calc(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Calc command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Calc command completed successfully");
     Ok(result)
 }
 
-/// Optimize tax deductions
+/// Calculate taxes for many parameter sets at once, run through a bounded
+/// worker pool instead of one sequential call per point — built for
+/// chart-building (e.g. marginal rate across an income range). One point
+/// failing in the CLI doesn't fail the batch: each slot carries its own
+/// `Result`. Parameters are validated up front, though, and a single point
+/// failing that validation rejects the whole batch before anything runs.
 #[tauri::command]
-pub async fn optimize(state: State<'_, CliState>, params: OptimizeParams) -> Result<OptimizeResult, String> {
+pub async fn calc_batch(
+    state: State<'_, CliState>,
+    params: Vec<CalcParams>,
+    concurrency: Option<usize>,
+) -> Result<Vec<Result<CalcResult, TaxGlideError>>, TaxGlideError> {
+    info!("Processing calc_batch command: {} item(s)", params.len());
+
+    for p in &params {
+        validate_income_params(p.income, p.income_sg, p.income_fed)?;
+    }
+
+    let cli_lock = state.cli.read().await;
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let results = cli.calc_batch(params, concurrency).await;
+
+    info!("Calc_batch command completed");
+    Ok(results.into_iter().map(|r| r.map_err(TaxGlideError::from)).collect())
+}
+
+/// Optimize tax deductions, relaying progress as `"optimize-progress"` events
+/// so the frontend can show a live progress bar during a long sweep.
+#[tauri::command]
+pub async fn optimize(
+    window: Window,
+    state: State<'_, CliState>,
+    params: OptimizeParams,
+) -> Result<OptimizeResult, TaxGlideError> {
     info!("Processing optimize command: {:?}", params);
-    
+
     // Validate parameters to prevent integer overflow
     validate_income_params(params.income, params.income_sg, params.income_fed)?;
     validate_deduction_params(params.max_deduction, params.step)?;
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.optimize(params).await.map_err(|e| {
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let (progress_tx, progress_rx) = mpsc::channel(16);
+    spawn_progress_relay(window, "optimize-progress", progress_rx);
+
+    let (request_id, cancel) = state.optimize_inflight.start().await;
+    let result = retry(&state.long_running_retry, || {
+        cli.optimize(params.clone(), Some(progress_tx.clone()), Some(&cancel))
+    })
+    .await;
+    state.optimize_inflight.finish(request_id).await;
+
+    let result = result.map_err(|e| {
         error!("Optimize command failed: {}", e);
-        format!("Optimization failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     info!("Optimize command completed successfully");
     Ok(result)
 }
 
+/// Forward every `ProgressUpdate` sent on `rx` to the frontend as a `name`
+/// window event, until the sender side is dropped (the command returns).
+fn spawn_progress_relay(window: Window, name: &'static str, mut rx: mpsc::Receiver<ProgressUpdate>) {
+    tokio::spawn(async move {
+        while let Some(update) = rx.recv().await {
+            if let Err(e) = window.emit(name, &update) {
+                error!("Failed to emit {} event: {}", name, e);
+            }
+        }
+    });
+}
+
 /// Validate numeric parameters to prevent integer overflow
-fn validate_income_params(income: Option<i32>, income_sg: Option<i32>, income_fed: Option<i32>) -> Result<(), String> {
-    // Check for i32 overflow (2,147,483,647 is the max)
-    let max_reasonable_income = 2_000_000_000; // 2 billion CHF - still absurd but prevents overflow
-    
+fn validate_income_params(
+    income: Option<i32>,
+    income_sg: Option<i32>,
+    income_fed: Option<i32>,
+) -> Result<(), TaxGlideError> {
     if let Some(inc) = income {
-        if inc < 0 {
-            return Err("Income cannot be negative. Even tax authorities aren't that generous!".to_string());
-        }
-        if inc > max_reasonable_income {
-            return Err("ðŸ¤‘ You're too freaking rich, just pay your taxes! (Income exceeds system limits)".to_string());
-        }
+        check_amount_bounds("income", inc)?;
     }
-    
     if let Some(inc_sg) = income_sg {
-        if inc_sg < 0 {
-            return Err("Cantonal income cannot be negative. Even tax authorities aren't that generous!".to_string());
-        }
-        if inc_sg > max_reasonable_income {
-            return Err("ðŸ¤‘ You're too freaking rich, just pay your taxes! (Cantonal income exceeds system limits)".to_string());
-        }
+        check_amount_bounds("income_sg", inc_sg)?;
     }
-    
     if let Some(inc_fed) = income_fed {
-        if inc_fed < 0 {
-            return Err("Federal income cannot be negative. Even tax authorities aren't that generous!".to_string());
-        }
-        if inc_fed > max_reasonable_income {
-            return Err("ðŸ¤‘ You're too freaking rich, just pay your taxes! (Federal income exceeds system limits)".to_string());
-        }
+        check_amount_bounds("income_fed", inc_fed)?;
     }
-    
+
     Ok(())
 }
 
-fn validate_deduction_params(max_deduction: i32, d_step: Option<i32>) -> Result<(), String> {
-    let max_reasonable_deduction = 2_000_000_000; // 2 billion CHF
-    
-    if max_deduction < 0 {
-        return Err("Max deduction cannot be negative. That would be... weird.".to_string());
-    }
-    
-    if max_deduction > max_reasonable_deduction {
-        return Err("ðŸ¤‘ You're too freaking rich, just pay your taxes! (Max deduction exceeds system limits)".to_string());
-    }
-    
+fn validate_deduction_params(max_deduction: i32, d_step: Option<i32>) -> Result<(), TaxGlideError> {
+    check_amount_bounds("max_deduction", max_deduction)?;
+
     if let Some(step) = d_step {
         if step <= 0 {
-            return Err("Deduction step must be positive. Zero steps won't get you anywhere!".to_string());
-        }
-        if step > max_reasonable_deduction {
-            return Err("ðŸ¤‘ Deduction step is ridiculously large. Just pay your taxes!".to_string());
+            return Err(TaxGlideError::Validation {
+                field: "step".to_string(),
+                reason: "Deduction step must be positive. Zero steps won't get you anywhere!".to_string(),
+            });
         }
+        check_amount_bounds("step", step)?;
     }
-    
+
     Ok(())
 }
 
-/// Scan deduction ranges
+/// Parse a locale-formatted CHF amount (e.g. `"120'000"`, `"120 000"`,
+/// `"120,000.00 CHF"`) typed by the user into an `i32`. Lets the frontend
+/// pass exactly what the user typed and get a precise, field-level error
+/// back instead of pre-sanitizing the input itself.
 #[tauri::command]
-pub async fn scan(state: State<'_, CliState>, params: ScanParams) -> Result<ScanResult, String> {
+pub fn parse_chf_amount_input(field: String, value: String) -> Result<i32, TaxGlideError> {
+    parse_chf_amount(&field, &value)
+}
+
+/// Scan deduction ranges, relaying progress as `"scan-progress"` events so
+/// the frontend can show a live progress bar during a long sweep.
+#[tauri::command]
+pub async fn scan(window: Window, state: State<'_, CliState>, params: ScanParams) -> Result<ScanResult, TaxGlideError> {
     info!("Processing scan command: {:?}", params);
-    
+
     // Validate parameters to prevent integer overflow
     validate_income_params(params.income, params.income_sg, params.income_fed)?;
     validate_deduction_params(params.max_deduction, params.d_step)?;
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.scan(params).await.map_err(|e| {
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let (progress_tx, progress_rx) = mpsc::channel(16);
+    spawn_progress_relay(window, "scan-progress", progress_rx);
+
+    let (request_id, cancel) = state.scan_inflight.start().await;
+    let result = retry(&state.long_running_retry, || {
+        cli.scan(params.clone(), Some(progress_tx.clone()), Some(&cancel))
+    })
+    .await;
+    state.scan_inflight.finish(request_id).await;
+
+    let result = result.map_err(|e| {
         error!("Scan command failed: {}", e);
-        format!("Scan failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     info!("Scan command completed successfully");
     Ok(result)
 }
 
+/// Scan deduction ranges for many parameter sets at once, run through a
+/// bounded worker pool instead of one sequential call per point. Same
+/// batching semantics as [`calc_batch`]; runs without progress streaming
+/// since that's a per-call concern that doesn't map onto a whole batch.
+#[tauri::command]
+pub async fn scan_batch(
+    state: State<'_, CliState>,
+    params: Vec<ScanParams>,
+    concurrency: Option<usize>,
+) -> Result<Vec<Result<ScanResult, TaxGlideError>>, TaxGlideError> {
+    info!("Processing scan_batch command: {} item(s)", params.len());
+
+    for p in &params {
+        validate_income_params(p.income, p.income_sg, p.income_fed)?;
+        validate_deduction_params(p.max_deduction, p.d_step)?;
+    }
+
+    let cli_lock = state.cli.read().await;
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let results = cli.scan_batch(params, concurrency).await;
+
+    info!("Scan_batch command completed");
+    Ok(results.into_iter().map(|r| r.map_err(TaxGlideError::from)).collect())
+}
+
 /// Compare tax brackets
 #[tauri::command]
 pub async fn compare_brackets(
-    state: State<'_, CliState>, 
-    params: CompareBracketsParams
-) -> Result<CompareBracketsResult, String> {
+    state: State<'_, CliState>,
+    params: CompareBracketsParams,
+) -> Result<CompareBracketsResult, TaxGlideError> {
     info!("Processing compare_brackets command: {:?}", params);
-    
+
     // Validate parameters to prevent integer overflow
     validate_income_params(params.income, params.income_sg, params.income_fed)?;
     if let Some(deduction) = params.deduction {
         validate_deduction_params(deduction, None)?;
     }
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.compare_brackets(params).await.map_err(|e| {
-        error!("Compare brackets command failed: {}", e);
-        format!("Compare brackets failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.read_retry, || cli.compare_brackets(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Compare brackets command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Compare brackets command completed successfully");
     Ok(result)
 }
@@ -209,49 +334,51 @@ pub async fn compare_brackets(
 /// Validate configuration
 #[tauri::command]
 pub async fn validate_config(
-    state: State<'_, CliState>, 
-    params: ValidateParams
-) -> Result<ValidateResult, String> {
+    state: State<'_, CliState>,
+    params: ValidateParams,
+) -> Result<ValidateResult, TaxGlideError> {
     info!("Processing validate command: {:?}", params);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.validate(params).await.map_err(|e| {
-        error!("Validate command failed: {}", e);
-        format!("Validation failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.read_retry, || cli.validate(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Validate command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Validate command completed successfully");
     Ok(result)
 }
 
 /// Check if CLI is initialized and ready
 #[tauri::command]
-pub async fn is_cli_ready(state: State<'_, CliState>) -> Result<bool, String> {
+pub async fn is_cli_ready(state: State<'_, CliState>) -> Result<bool, TaxGlideError> {
     let cli_lock = state.cli.read().await;
     Ok(cli_lock.is_some())
 }
 
 /// Get CLI status information
 #[tauri::command]
-pub async fn get_cli_status(state: State<'_, CliState>) -> Result<CliStatusInfo, String> {
+pub async fn get_cli_status(state: State<'_, CliState>) -> Result<CliStatusInfo, TaxGlideError> {
     let cli_lock = state.cli.read().await;
-    
+
     match cli_lock.as_ref() {
         Some(cli) => {
             let version_info = cli.get_version_info().cloned();
             Ok(CliStatusInfo {
                 initialized: true,
                 version_info,
+                cli_source: Some(cli.get_cli_source().clone()),
                 error: None,
             })
         }
         None => Ok(CliStatusInfo {
             initialized: false,
             version_info: None,
+            cli_source: None,
             error: Some("CLI not initialized".to_string()),
         }),
     }
@@ -260,21 +387,19 @@ pub async fn get_cli_status(state: State<'_, CliState>) -> Result<CliStatusInfo,
 /// Get available cantons and municipalities
 #[tauri::command]
 pub async fn get_available_locations(
-    state: State<'_, CliState>
-) -> Result<crate::cli_types::AvailableLocations, String> {
+    state: State<'_, CliState>,
+) -> Result<crate::cli_types::AvailableLocations, TaxGlideError> {
     info!("Loading available cantons and municipalities from CLI...");
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
     // Call CLI locations command
     let result = cli.get_available_locations().await.map_err(|e| {
         error!("Get locations command failed: {}", e);
-        format!("Get locations failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     info!("Available locations loaded successfully from CLI");
     Ok(result)
 }
@@ -283,21 +408,17 @@ pub async fn get_available_locations(
 
 /// List available tax years
 #[tauri::command]
-pub async fn list_years(
-    state: State<'_, CliState>
-) -> Result<crate::cli_types::AvailableYears, String> {
+pub async fn list_years(state: State<'_, CliState>) -> Result<crate::cli_types::AvailableYears, TaxGlideError> {
     info!("Loading available tax years from CLI...");
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
     let result = cli.list_years().await.map_err(|e| {
         error!("List years command failed: {}", e);
-        format!("List years failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     info!("Available years loaded successfully from CLI");
     Ok(result)
 }
@@ -306,20 +427,18 @@ pub async fn list_years(
 #[tauri::command]
 pub async fn get_config_summary(
     state: State<'_, CliState>,
-    params: crate::cli_types::ConfigSummaryParams
-) -> Result<crate::cli_types::ConfigSummary, String> {
+    params: crate::cli_types::ConfigSummaryParams,
+) -> Result<crate::cli_types::ConfigSummary, TaxGlideError> {
     info!("Loading configuration summary for year {} from CLI...", params.year);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
     let result = cli.get_config_summary(params).await.map_err(|e| {
         error!("Get config summary command failed: {}", e);
-        format!("Get config summary failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     info!("Configuration summary loaded successfully from CLI");
     Ok(result)
 }
@@ -328,20 +447,20 @@ pub async fn get_config_summary(
 #[tauri::command]
 pub async fn create_year(
     state: State<'_, CliState>,
-    params: crate::cli_types::CreateYearParams
-) -> Result<crate::cli_types::YearOperationResult, String> {
+    params: crate::cli_types::CreateYearParams,
+) -> Result<crate::cli_types::YearOperationResult, TaxGlideError> {
     info!("Creating tax year {} from {} via CLI...", params.target_year, params.source_year);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.create_year(params).await.map_err(|e| {
-        error!("Create year command failed: {}", e);
-        format!("Create year failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.create_year(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Create year command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Tax year created successfully via CLI");
     Ok(result)
 }
@@ -350,20 +469,23 @@ pub async fn create_year(
 #[tauri::command]
 pub async fn update_federal_brackets(
     state: State<'_, CliState>,
-    params: crate::cli_types::UpdateFederalBracketsParams
-) -> Result<crate::cli_types::FederalBracketsOperationResult, String> {
-    info!("Updating federal brackets for {} filing status in year {} via CLI...", params.filing_status, params.year);
-    
+    params: crate::cli_types::UpdateFederalBracketsParams,
+) -> Result<crate::cli_types::FederalBracketsOperationResult, TaxGlideError> {
+    info!(
+        "Updating federal brackets for {} filing status in year {} via CLI...",
+        params.filing_status, params.year
+    );
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.update_federal_brackets(params).await.map_err(|e| {
-        error!("Update federal brackets command failed: {}", e);
-        format!("Update federal brackets failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.update_federal_brackets(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Update federal brackets command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Federal brackets updated successfully via CLI");
     Ok(result)
 }
@@ -372,20 +494,20 @@ pub async fn update_federal_brackets(
 #[tauri::command]
 pub async fn create_canton(
     state: State<'_, CliState>,
-    params: crate::cli_types::CreateCantonParams
-) -> Result<crate::cli_types::CantonOperationResult, String> {
+    params: crate::cli_types::CreateCantonParams,
+) -> Result<crate::cli_types::CantonOperationResult, TaxGlideError> {
     info!("Creating canton {} in year {} via CLI...", params.canton_key, params.year);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.create_canton(params).await.map_err(|e| {
-        error!("Create canton command failed: {}", e);
-        format!("Create canton failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.create_canton(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Create canton command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Canton created successfully via CLI");
     Ok(result)
 }
@@ -394,20 +516,20 @@ pub async fn create_canton(
 #[tauri::command]
 pub async fn update_canton(
     state: State<'_, CliState>,
-    params: crate::cli_types::UpdateCantonParams
-) -> Result<crate::cli_types::CantonOperationResult, String> {
+    params: crate::cli_types::UpdateCantonParams,
+) -> Result<crate::cli_types::CantonOperationResult, TaxGlideError> {
     info!("Updating canton {} in year {} via CLI...", params.canton_key, params.year);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.update_canton(params).await.map_err(|e| {
-        error!("Update canton command failed: {}", e);
-        format!("Update canton failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.update_canton(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Update canton command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Canton updated successfully via CLI");
     Ok(result)
 }
@@ -416,20 +538,20 @@ pub async fn update_canton(
 #[tauri::command]
 pub async fn delete_canton(
     state: State<'_, CliState>,
-    params: crate::cli_types::DeleteCantonParams
-) -> Result<crate::cli_types::CantonOperationResult, String> {
+    params: crate::cli_types::DeleteCantonParams,
+) -> Result<crate::cli_types::CantonOperationResult, TaxGlideError> {
     info!("Deleting canton {} from year {} via CLI...", params.canton_key, params.year);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.delete_canton(params).await.map_err(|e| {
-        error!("Delete canton command failed: {}", e);
-        format!("Delete canton failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.delete_canton(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Delete canton command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Canton deleted successfully via CLI");
     Ok(result)
 }
@@ -438,20 +560,23 @@ pub async fn delete_canton(
 #[tauri::command]
 pub async fn create_municipality(
     state: State<'_, CliState>,
-    params: crate::cli_types::CreateMunicipalityParams
-) -> Result<crate::cli_types::MunicipalityOperationResult, String> {
-    info!("Creating municipality {} in canton {} for year {} via CLI...", params.municipality_key, params.canton_key, params.year);
-    
+    params: crate::cli_types::CreateMunicipalityParams,
+) -> Result<crate::cli_types::MunicipalityOperationResult, TaxGlideError> {
+    info!(
+        "Creating municipality {} in canton {} for year {} via CLI...",
+        params.municipality_key, params.canton_key, params.year
+    );
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.create_municipality(params).await.map_err(|e| {
-        error!("Create municipality command failed: {}", e);
-        format!("Create municipality failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.create_municipality(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Create municipality command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Municipality created successfully via CLI");
     Ok(result)
 }
@@ -460,20 +585,23 @@ pub async fn create_municipality(
 #[tauri::command]
 pub async fn update_municipality(
     state: State<'_, CliState>,
-    params: crate::cli_types::UpdateMunicipalityParams
-) -> Result<crate::cli_types::MunicipalityOperationResult, String> {
-    info!("Updating municipality {} in canton {} for year {} via CLI...", params.municipality_key, params.canton_key, params.year);
-    
+    params: crate::cli_types::UpdateMunicipalityParams,
+) -> Result<crate::cli_types::MunicipalityOperationResult, TaxGlideError> {
+    info!(
+        "Updating municipality {} in canton {} for year {} via CLI...",
+        params.municipality_key, params.canton_key, params.year
+    );
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
-    let result = cli.update_municipality(params).await.map_err(|e| {
-        error!("Update municipality command failed: {}", e);
-        format!("Update municipality failed: {}", e)
-    })?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
+    let result = retry(&state.write_retry, || cli.update_municipality(params.clone()))
+        .await
+        .map_err(|e| {
+            error!("Update municipality command failed: {}", e);
+            TaxGlideError::from(e)
+        })?;
+
     info!("Municipality updated successfully via CLI");
     Ok(result)
 }
@@ -482,20 +610,21 @@ pub async fn update_municipality(
 #[tauri::command]
 pub async fn get_federal_segments(
     state: State<'_, CliState>,
-    params: crate::cli_types::GetFederalSegmentsParams
-) -> Result<crate::cli_types::FederalSegmentsResult, String> {
-    info!("Getting federal segments for {} filing status in year {} via CLI...", params.filing_status, params.year);
-    
+    params: crate::cli_types::GetFederalSegmentsParams,
+) -> Result<crate::cli_types::FederalSegmentsResult, TaxGlideError> {
+    info!(
+        "Getting federal segments for {} filing status in year {} via CLI...",
+        params.filing_status, params.year
+    );
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
     let result = cli.get_federal_segments(params).await.map_err(|e| {
         error!("Get federal segments command failed: {}", e);
-        format!("Get federal segments failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     info!("Federal segments loaded successfully via CLI");
     Ok(result)
 }
@@ -505,29 +634,27 @@ pub async fn get_federal_segments(
 pub async fn cli_get_canton(
     state: State<'_, CliState>,
     year: i32,
-    canton_key: String
-) -> Result<String, String> {
+    canton_key: String,
+) -> Result<String, TaxGlideError> {
     info!("Getting canton details for {} in year {} via CLI...", canton_key, year);
-    
+
     let cli_lock = state.cli.read().await;
-    let cli = cli_lock
-        .as_ref()
-        .ok_or_else(|| "CLI not initialized. Call init_cli first.".to_string())?;
-    
+    let cli = cli_lock.as_ref().ok_or(TaxGlideError::NotInitialized)?;
+
     let params = crate::cli_types::GetCantonParams {
         year,
         canton_key: canton_key.clone(),
     };
-    
+
     let result = cli.get_canton(params).await.map_err(|e| {
         error!("Get canton command failed: {}", e);
-        format!("Get canton failed: {}", e)
+        TaxGlideError::from(e)
     })?;
-    
+
     // Return JSON string for easy consumption by frontend
     serde_json::to_string(&result).map_err(|e| {
         error!("Failed to serialize canton details: {}", e);
-        format!("Serialization failed: {}", e)
+        TaxGlideError::Deserialize(e.to_string())
     })
 }
 
@@ -535,5 +662,8 @@ pub async fn cli_get_canton(
 pub struct CliStatusInfo {
     pub initialized: bool,
     pub version_info: Option<VersionInfo>,
+    /// Whether the CLI is the bundled sidecar, a dev checkout, or a system
+    /// install, so the UI can show "using bundled CLI vX" vs "using system CLI".
+    pub cli_source: Option<CliSource>,
     pub error: Option<String>,
 }